@@ -64,3 +64,65 @@ float4 __attribute__((kernel)) testLgammaFloat4Int4Float4(float4 inV, unsigned i
     rsSetElementAt_int4(gAllocOutSignOfGamma, outSignOfGamma, x);
     return out;
 }
+
+float __attribute__((kernel)) testTgammaFloatFloat(float inV) {
+    return tgamma(inV);
+}
+
+float2 __attribute__((kernel)) testTgammaFloat2Float2(float2 inV) {
+    return tgamma(inV);
+}
+
+float3 __attribute__((kernel)) testTgammaFloat3Float3(float3 inV) {
+    return tgamma(inV);
+}
+
+float4 __attribute__((kernel)) testTgammaFloat4Float4(float4 inV) {
+    return tgamma(inV);
+}
+
+#if (defined(RS_VERSION) && (RS_VERSION >= 24))
+half __attribute__((kernel)) testLgammaHalfHalf(half inV) {
+    return lgamma(inV);
+}
+
+half2 __attribute__((kernel)) testLgammaHalf2Half2(half2 inV) {
+    return lgamma(inV);
+}
+
+half3 __attribute__((kernel)) testLgammaHalf3Half3(half3 inV) {
+    return lgamma(inV);
+}
+
+half4 __attribute__((kernel)) testLgammaHalf4Half4(half4 inV) {
+    return lgamma(inV);
+}
+
+half __attribute__((kernel)) testLgammaHalfIntHalf(half inV, unsigned int x) {
+    int outSignOfGamma = 0;
+    half out = lgamma(inV, &outSignOfGamma);
+    rsSetElementAt_int(gAllocOutSignOfGamma, outSignOfGamma, x);
+    return out;
+}
+
+half2 __attribute__((kernel)) testLgammaHalf2Int2Half2(half2 inV, unsigned int x) {
+    int2 outSignOfGamma = 0;
+    half2 out = lgamma(inV, &outSignOfGamma);
+    rsSetElementAt_int2(gAllocOutSignOfGamma, outSignOfGamma, x);
+    return out;
+}
+
+half3 __attribute__((kernel)) testLgammaHalf3Int3Half3(half3 inV, unsigned int x) {
+    int3 outSignOfGamma = 0;
+    half3 out = lgamma(inV, &outSignOfGamma);
+    rsSetElementAt_int3(gAllocOutSignOfGamma, outSignOfGamma, x);
+    return out;
+}
+
+half4 __attribute__((kernel)) testLgammaHalf4Int4Half4(half4 inV, unsigned int x) {
+    int4 outSignOfGamma = 0;
+    half4 out = lgamma(inV, &outSignOfGamma);
+    rsSetElementAt_int4(gAllocOutSignOfGamma, outSignOfGamma, x);
+    return out;
+}
+#endif